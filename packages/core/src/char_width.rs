@@ -0,0 +1,113 @@
+/// East Asian Widthに基づく文字幅判定
+///
+/// 等幅フォントを前提としたレンダラー (ターミナル出力、Markdownテーブルの
+/// 桁揃えなど) では、CJK文字は半角文字の2倍のセル幅を占有します。
+/// `str::len`や`chars().count()`はこの違いを考慮しないため、全角文字を
+/// 含む文章では桁がずれてしまいます。
+///
+/// ## 幅2 (Wide) として扱う範囲
+/// - ひらがな (U+3041-U+3096)
+/// - カタカナ (U+309B-U+30FF) - 濁点・半濁点・繰返し記号を含む
+/// - 囲みCJK文字・月 + CJK互換性 (U+3200-U+33FF)
+/// - CJK統合漢字拡張A (U+3400-U+4DBF)
+/// - CJK統合漢字 (U+4E00-U+9FFF)
+/// - ハングル音節 (U+AC00-U+D7A3)
+/// - CJK互換漢字 (U+F900-U+FAFF)
+/// - CJK互換形式 (U+FE30-U+FE6B)
+/// - 全角ASCII・記号 (U+FF01-U+FF60)
+/// - 全角通貨記号 (U+FFE0-U+FFE6)
+/// - CJK統合漢字拡張B〜I (U+20000-U+3FFFD)
+///
+/// ## 幅1 (Narrow) のまま扱う範囲
+/// - 半角カナ・半角ハングル (U+FF61-U+FFDC) - 見た目どおり半角セル
+/// - ASCII・Latin文字全般
+///
+/// ## 幅0 (Zero) として扱う範囲
+/// - ひらがな・カタカナの結合濁点/半濁点 (U+3099-U+309A)
+///
+/// 分類は[`crate::is_cjk::cjk_script`]が使うのと同じ文字体系の区分けを
+/// 踏襲しているが、目的が異なる (スクリプト分類 vs. 表示幅) ため独立した
+/// 範囲テーブルとして実装している。
+///
+/// ## スコープ / 未接続 (TODO)
+/// このコミットは幅計算ロジック本体のみを実装したものであり、「GFMテーブル
+/// レンダラーの列幅計算に組み込む」部分は意図的に未着手のまま残している。
+/// このクレートにはまだGFMテーブルレンダラー・列幅計算ロジックが存在しない
+/// ため、`char_width`/`display_width`は現時点ではどこからも呼ばれていない。
+/// テーブルレンダラーが実装され次第、その列幅計算とセパレーター行生成を
+/// ここに差し替えること。
+pub fn char_width(c: char) -> usize {
+    match u32::from(c) {
+        // Combining Hiragana/Katakana voiced and semi-voiced sound marks
+        0x3099..=0x309a => 0,
+        0x3041..=0x3096 // Hiragana
+        | 0x309b..=0x30ff // Katakana (incl. voiced sound marks, iteration marks)
+        | 0x3200..=0x33ff // Enclosed CJK Letters and Months + CJK Compatibility
+        | 0x3400..=0x4dbf // CJK Unified Ideographs Extension A
+        | 0x4e00..=0x9fff // CJK Unified Ideographs
+        | 0xac00..=0xd7a3 // Hangul Syllables
+        | 0xf900..=0xfaff // CJK Compatibility Ideographs
+        | 0xfe30..=0xfe6b // CJK Compatibility Forms
+        | 0xff01..=0xff60 // Fullwidth ASCII, punctuation and brackets
+        | 0xffe0..=0xffe6 // Fullwidth currency symbols
+        | 0x20000..=0x3fffd // CJK Unified Ideographs Extension B-I
+            => 2,
+        _ => 1,
+    }
+}
+
+/// 文字列全体の表示幅 (セル数) を計算する
+///
+/// 各文字の[`char_width`]を合算するだけの薄いラッパーで、Markdownテーブルの
+/// 列幅計算や区切り行生成で、パディングに必要なセル数を求めるために使う。
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{char_width, display_width};
+
+    #[test]
+    fn narrow_ascii_is_width_one() {
+        assert_eq!(char_width('a'), 1);
+        assert_eq!(char_width('Z'), 1);
+        assert_eq!(char_width('1'), 1);
+        assert_eq!(char_width('!'), 1);
+        assert_eq!(char_width(' '), 1);
+    }
+
+    #[test]
+    fn wide_cjk_is_width_two() {
+        assert_eq!(char_width('あ'), 2); // Hiragana
+        assert_eq!(char_width('ア'), 2); // Katakana
+        assert_eq!(char_width('漢'), 2); // CJK Unified Ideograph
+        assert_eq!(char_width('가'), 2); // Hangul Syllable
+        assert_eq!(char_width('\u{F900}'), 2); // CJK Compatibility Ideograph
+        assert_eq!(char_width('\u{FF21}'), 2); // Fullwidth Latin Capital A
+        assert_eq!(char_width('\u{FFE5}'), 2); // Fullwidth Yen Sign
+        assert_eq!(char_width('\u{20000}'), 2); // CJK Extension B
+    }
+
+    #[test]
+    fn halfwidth_katakana_stays_narrow() {
+        assert_eq!(char_width('\u{FF71}'), 1); // ｱ - Halfwidth Katakana A
+        assert_eq!(char_width('\u{FFDC}'), 1); // Halfwidth Hangul Letter I
+    }
+
+    #[test]
+    fn combining_marks_are_zero_width() {
+        assert_eq!(char_width('\u{3099}'), 0); // Combining Voiced Sound Mark
+        assert_eq!(char_width('\u{309A}'), 0); // Combining Semi-Voiced Sound Mark
+    }
+
+    #[test]
+    fn display_width_sums_mixed_text() {
+        // "A" (1) + "漢" (2) + "字" (2) = 5
+        assert_eq!(display_width("A漢字"), 5);
+        // Decomposed "が" as base kana + combining voiced mark: 2 + 0 = 2
+        assert_eq!(display_width("\u{304B}\u{3099}"), 2);
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("Hello"), 5);
+    }
+}
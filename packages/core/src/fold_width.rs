@@ -0,0 +1,185 @@
+/// 全角⇔半角の正規化 (幅の畳み込み)
+///
+/// 見出しに全角ASCII (`Ｈｅｌｌｏ`、`！`) や半角カタカナが混ざっていると、
+/// 見た目は同じでも内部コードが異なるため、スラッグ生成の結果が
+/// 半角/全角の表記ゆれでばらついてしまう。このモジュールはそれらを
+/// 正規の幅に畳み込み、スラッグ生成の前処理として使う。
+///
+/// ## 変換規則
+/// - 全角ASCII (U+FF01-U+FF5E) は `0xFEE0`を引いて半角ASCII (U+0021-U+007E) へ
+/// - 全角スペース (U+3000) は半角スペース (U+0020) へ
+/// - 半角カタカナ (U+FF61-U+FF9F) は対応する全角カタカナへ展開する。
+///   半角濁点 (U+FF9E) / 半角半濁点 (U+FF9F) は直前の仮名と結合できる場合、
+///   単独の濁点・半濁点文字ではなく濁音/半濁音の全角カタカナ一文字にする
+///   (例: `ｶﾞ` → `ガ`)。結合できる前の仮名が無い単独のマークは、Unicodeの
+///   互換分解(`UnicodeData.txt`)が定める結合濁点/結合半濁点
+///   (U+3099/U+309A) へ畳み込む (スペーシング形のU+309B/U+309Cではない)
+///
+/// 上記以外の文字 (本来のCJK文字、Latin文字など) はそのまま変化しない。
+///
+/// ## スコープ / 未接続 (TODO)
+/// このコミットは正規化ロジック本体のみを実装したものであり、「スラッグ
+/// ビルダーのオプトイン前処理として組み込む」部分は意図的に未着手のまま
+/// 残している。このクレートにはまだスラッグビルダーが存在しないため、
+/// `fold_width`/`fold_width_str`は現時点ではどこからも呼ばれていない。
+/// スラッグビルダーが実装され次第、そのオプトイン前処理としてここを
+/// 呼び出すこと。
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// 半角カタカナ (U+FF61-U+FF9D) から対応する全角カタカナ (濁点・半濁点を
+/// 含まない基本形) への変換表。
+fn halfwidth_katakana_base(c: char) -> Option<char> {
+    let table: [char; 61] = [
+        '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ',
+        'ー', 'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ',
+        'ソ', 'タ', 'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ',
+        'ホ', 'マ', 'ミ', 'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ',
+        'ン',
+    ];
+    let idx = u32::from(c).checked_sub(0xff61)?;
+    table.get(idx as usize).copied()
+}
+
+/// 基本形の全角カタカナに半角濁点 (U+FF9E) を重ねたときの濁音形。
+fn voiced(base: char) -> Option<char> {
+    static TABLE: OnceLock<HashMap<char, char>> = OnceLock::new();
+    TABLE
+        .get_or_init(|| {
+            [
+                ('カ', 'ガ'), ('キ', 'ギ'), ('ク', 'グ'), ('ケ', 'ゲ'), ('コ', 'ゴ'),
+                ('サ', 'ザ'), ('シ', 'ジ'), ('ス', 'ズ'), ('セ', 'ゼ'), ('ソ', 'ゾ'),
+                ('タ', 'ダ'), ('チ', 'ヂ'), ('ツ', 'ヅ'), ('テ', 'デ'), ('ト', 'ド'),
+                ('ハ', 'バ'), ('ヒ', 'ビ'), ('フ', 'ブ'), ('ヘ', 'ベ'), ('ホ', 'ボ'),
+                ('ウ', 'ヴ'),
+            ]
+            .into_iter()
+            .collect()
+        })
+        .get(&base)
+        .copied()
+}
+
+/// 基本形の全角カタカナに半角半濁点 (U+FF9F) を重ねたときの半濁音形。
+fn semi_voiced(base: char) -> Option<char> {
+    static TABLE: OnceLock<HashMap<char, char>> = OnceLock::new();
+    TABLE
+        .get_or_init(|| {
+            [('ハ', 'パ'), ('ヒ', 'ピ'), ('フ', 'プ'), ('ヘ', 'ペ'), ('ホ', 'ポ')]
+                .into_iter()
+                .collect()
+        })
+        .get(&base)
+        .copied()
+}
+
+/// 1文字単位の幅の畳み込み
+///
+/// 半角カタカナは(濁点・半濁点との結合を行わず)基本形の全角カタカナへ展開する。
+/// 直前の仮名との結合が必要な場合は[`fold_width_str`]を使うこと。
+pub fn fold_width(c: char) -> char {
+    match u32::from(c) {
+        0x3000 => ' ',
+        0xff01..=0xff5e => {
+            char::from_u32(u32::from(c) - 0xfee0).expect("fullwidth ASCII maps into ASCII range")
+        }
+        // Standalone halfwidth voiced/semi-voiced sound marks: Unicode's own
+        // compatibility decomposition (`UnicodeData.txt`) maps these to the
+        // combining marks, not the spacing forms U+309B/U+309C.
+        0xff9e => '\u{3099}',
+        0xff9f => '\u{309a}',
+        0xff61..=0xff9d => halfwidth_katakana_base(c).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// 文字列全体の幅の畳み込み
+///
+/// [`fold_width`]を各文字に適用しつつ、半角濁点 (U+FF9E) / 半角半濁点
+/// (U+FF9F) が直前の半角カタカナと結合可能な場合は、単独の濁点文字ではなく
+/// 濁音/半濁音の全角カタカナ一文字に結合する。
+pub fn fold_width_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if let Some(base) = halfwidth_katakana_base(c) {
+            match chars.peek() {
+                Some('\u{ff9e}') => {
+                    if let Some(v) = voiced(base) {
+                        out.push(v);
+                        chars.next();
+                        continue;
+                    }
+                }
+                Some('\u{ff9f}') => {
+                    if let Some(v) = semi_voiced(base) {
+                        out.push(v);
+                        chars.next();
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            out.push(base);
+        } else {
+            out.push(fold_width(c));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold_width, fold_width_str};
+
+    #[test]
+    fn folds_fullwidth_ascii_to_halfwidth() {
+        assert_eq!(fold_width('Ａ'), 'A');
+        assert_eq!(fold_width('ａ'), 'a');
+        assert_eq!(fold_width('１'), '1');
+        assert_eq!(fold_width('！'), '!');
+        assert_eq!(fold_width('～'), '~');
+    }
+
+    #[test]
+    fn folds_fullwidth_space() {
+        assert_eq!(fold_width('\u{3000}'), ' ');
+    }
+
+    #[test]
+    fn leaves_genuine_cjk_untouched() {
+        assert_eq!(fold_width('漢'), '漢');
+        assert_eq!(fold_width('あ'), 'あ');
+        assert_eq!(fold_width('한'), '한');
+    }
+
+    #[test]
+    fn expands_halfwidth_katakana_to_fullwidth_base() {
+        assert_eq!(fold_width('\u{ff71}'), 'ア'); // ｱ -> ア
+        assert_eq!(fold_width('\u{ff76}'), 'カ'); // ｶ -> カ
+        assert_eq!(fold_width('\u{ff9d}'), 'ン'); // ﾝ -> ン
+    }
+
+    #[test]
+    fn combines_halfwidth_voiced_marks_in_strings() {
+        // ｶﾞ -> ガ
+        assert_eq!(fold_width_str("\u{ff76}\u{ff9e}"), "ガ");
+        // ﾊﾟ -> パ (semi-voiced)
+        assert_eq!(fold_width_str("\u{ff8a}\u{ff9f}"), "パ");
+        // ｳﾞ -> ヴ
+        assert_eq!(fold_width_str("\u{ff73}\u{ff9e}"), "ヴ");
+        // Unpaired mark with no combinable base stays as a standalone mark
+        // (ｱ has no voiced form, so the trailing ﾞ falls back to `fold_width`).
+        assert_eq!(fold_width_str("\u{ff71}\u{ff9e}"), "ア\u{3099}");
+        assert_eq!(fold_width('\u{ff9e}'), '\u{3099}');
+        assert_eq!(fold_width('\u{ff9f}'), '\u{309a}');
+    }
+
+    #[test]
+    fn collapses_slug_inputs_to_the_same_text() {
+        assert_eq!(fold_width_str("Ｔｅｓｔ"), "Test");
+        assert_eq!(fold_width_str("Hello！"), "Hello!");
+    }
+}
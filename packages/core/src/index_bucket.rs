@@ -0,0 +1,129 @@
+/// 目次(TOC)用のCJK見出しインデックスバケット
+///
+/// CJK見出しが多い文書でTOCを生成する際、アルファベット順ソートのような
+/// 単純な文字コード順では利用者の期待する並びにならない。このモジュールは
+/// 各見出しの先頭文字を代表インデックス(バケットキー)に丸め込み、TOC生成側が
+/// その区切りで見出しをグループ化できるようにする。
+///
+/// ## バケット規則
+/// - ハングル音節は初声(先頭子音)に丸める。`choseong = (cp - 0xAC00) / 588`で
+///   19種の初声インデックスを求め、代表字母(濃音は基本字母へ畳み込む)を返す
+/// - ひらがな・カタカナは五十音の行(あ/か/さ/た/な/は/ま/や/ら/わ)に丸める
+/// - 漢字はそのまま(文字自体が代表インデックス)
+/// - Latin文字は先頭文字を大文字化する
+/// - 上記のいずれにも当てはまらない文字はそのまま返す
+///
+/// 安定したバケットキーを返すことが目的であり、バケットキー自体が表示用の
+/// 見出しとして使われることは想定していない。
+const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 濃音(ㄲㄸㅃㅆㅉ)をその基本字母へ畳み込む
+fn collapse_tensed(c: char) -> char {
+    match c {
+        'ㄲ' => 'ㄱ',
+        'ㄸ' => 'ㄷ',
+        'ㅃ' => 'ㅂ',
+        'ㅆ' => 'ㅅ',
+        'ㅉ' => 'ㅈ',
+        other => other,
+    }
+}
+
+fn hangul_bucket(c: char) -> char {
+    let choseong_index = (u32::from(c) - 0xac00) / 588;
+    collapse_tensed(CHOSEONG[choseong_index as usize])
+}
+
+/// ひらがな・カタカナを五十音の行に丸める。両方とも行の並び順が
+/// 同じオフセットで続く(カタカナはひらがな+0x60)ため、同じ境界値で判定できる。
+fn kana_bucket(c: char) -> Option<char> {
+    let offset = match u32::from(c) {
+        cp @ 0x3041..=0x3096 => cp,
+        cp @ 0x30a1..=0x30fa => cp - 0x60,
+        _ => return None,
+    };
+
+    Some(match offset {
+        0x3041..=0x304a => 'あ',
+        0x304b..=0x3054 => 'か',
+        0x3055..=0x305e => 'さ',
+        0x305f..=0x3069 => 'た',
+        0x306a..=0x306e => 'な',
+        0x306f..=0x307d => 'は',
+        0x307e..=0x3082 => 'ま',
+        0x3083..=0x3088 => 'や',
+        0x3089..=0x308d => 'ら',
+        // ゔ/ヴ belongs to the あ row; small ゕゖ/ヵヶ to the か row
+        0x3094 => 'あ',
+        0x3095..=0x3096 => 'か',
+        _ => 'わ',
+    })
+}
+
+/// 見出しの先頭文字をTOCの代表インデックスへ丸める
+pub fn index_bucket(c: char) -> char {
+    if (0xac00..=0xd7a3).contains(&u32::from(c)) {
+        return hangul_bucket(c);
+    }
+
+    if let Some(bucket) = kana_bucket(c) {
+        return bucket;
+    }
+
+    if c.is_alphabetic() && c.is_ascii() {
+        return c.to_ascii_uppercase();
+    }
+
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::index_bucket;
+
+    #[test]
+    fn buckets_hangul_by_choseong() {
+        assert_eq!(index_bucket('가'), 'ㄱ'); // choseong ㄱ
+        assert_eq!(index_bucket('나'), 'ㄴ');
+        assert_eq!(index_bucket('다'), 'ㄷ');
+        assert_eq!(index_bucket('하'), 'ㅎ');
+    }
+
+    #[test]
+    fn collapses_tensed_choseong_onto_base() {
+        assert_eq!(index_bucket('까'), 'ㄱ'); // ㄲ collapses to ㄱ
+        assert_eq!(index_bucket('따'), 'ㄷ'); // ㄸ collapses to ㄷ
+        assert_eq!(index_bucket('빠'), 'ㅂ'); // ㅃ collapses to ㅂ
+    }
+
+    #[test]
+    fn buckets_hiragana_and_katakana_by_gojuon_row() {
+        assert_eq!(index_bucket('あ'), 'あ');
+        assert_eq!(index_bucket('い'), 'あ');
+        assert_eq!(index_bucket('が'), 'か'); // voiced か-row kana still buckets to か
+        assert_eq!(index_bucket('ラ'), 'ら'); // katakana bucket uses hiragana representative
+        assert_eq!(index_bucket('ワ'), 'わ');
+    }
+
+    #[test]
+    fn han_falls_back_to_itself() {
+        assert_eq!(index_bucket('漢'), '漢');
+        assert_eq!(index_bucket('字'), '字');
+    }
+
+    #[test]
+    fn latin_uppercases_first_letter() {
+        assert_eq!(index_bucket('a'), 'A');
+        assert_eq!(index_bucket('z'), 'Z');
+        assert_eq!(index_bucket('M'), 'M');
+    }
+
+    #[test]
+    fn passes_through_unrecognized_characters() {
+        assert_eq!(index_bucket('1'), '1');
+        assert_eq!(index_bucket('#'), '#');
+    }
+}
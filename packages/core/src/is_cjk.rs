@@ -1,3 +1,120 @@
+/// CJKスクリプト分類
+///
+/// `cjk_script`が返す、文字が属する具体的な文字体系です。
+/// `is_cjk`の真偽値だけでは区別できない、スラッグ生成やレンダリングで
+/// 挙動を分けたいケース(ハングル音節は保持したいがカタカナは翻字したい、等)
+/// のために、同じ範囲テーブルをスクリプト単位に細分化しています。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CjkScript {
+    /// 漢字 (CJK統合漢字、拡張A〜I、部首、互換漢字を含む)
+    Han,
+    /// ひらがな
+    Hiragana,
+    /// カタカナ (結合記号・かな拡張を含む)
+    Katakana,
+    /// ハングル (字母・音節・互換字母・拡張字母を含む)
+    Hangul,
+    /// 注音符号 (ボポモフォ)
+    Bopomofo,
+    /// イ族文字 (Yi)
+    Yi,
+    /// 西夏文字 (Tangut)
+    Tangut,
+    /// 女書 (Nushu)
+    Nushu,
+    /// 漢文・CJK記号及び句読点
+    KanbunAndSymbols,
+    /// 全角形 (全角ASCII・全角記号など)
+    FullwidthForms,
+    /// 半角形 (半角カナ・半角ハングルなど)
+    HalfwidthForms,
+    /// 上記のいずれにも当てはまらないCJK関連記号・特殊文字
+    Other,
+}
+
+/// 文字が属するCJKスクリプトを判定する
+///
+/// `is_cjk`と同じ範囲テーブルをスクリプト単位に分割し、どの文字体系に
+/// 属するかを返します。CJK文字でない場合は`None`を返すため、
+/// `is_cjk(c) == cjk_script(c).is_some()`が常に成立します。
+pub fn cjk_script(c: char) -> Option<CjkScript> {
+    use CjkScript::*;
+
+    match u32::from(c) {
+        // Hangul Jamo
+        0x1100..=0x11ff => Some(Hangul),
+        // Won Sign / Angle Brackets / Trigrams / Digrams-Monograms
+        0x20a9 | 0x2329..=0x232a | 0x2630..=0x2637 | 0x268a..=0x268f => Some(Other),
+        // CJK Radicals Supplement + Kangxi Radicals (part of the Han writing system)
+        0x2e80..=0x2e99 | 0x2e9b..=0x2ef3 | 0x2f00..=0x2fd5 => Some(Han),
+        // Ideographic Description Characters + CJK Symbols and Punctuation
+        0x2ff0..=0x303e => Some(KanbunAndSymbols),
+        // Hiragana
+        0x3041..=0x3096 => Some(Hiragana),
+        // Combining Marks + Katakana
+        0x3099..=0x30ff => Some(Katakana),
+        // Bopomofo
+        0x3105..=0x312f => Some(Bopomofo),
+        // Hangul Compatibility Jamo
+        0x3131..=0x318e => Some(Hangul),
+        // Kanbun + CJK Strokes + Katakana Phonetic Extensions + Enclosed CJK Letters and Months (Part 1)
+        0x3190..=0x31e5 => Some(KanbunAndSymbols),
+        // Enclosed CJK Letters and Months (Part 2 and 3)
+        0x31ef..=0x321e | 0x3220..=0x3247 => Some(Other),
+        // CJK Compatibility + CJK Unified Ideographs (incl. Extension A) live in this span
+        0x3250..=0x9fff => Some(Han),
+        // Yi Syllables + Yi Radicals
+        0xa000..=0xa48c | 0xa490..=0xa4c6 => Some(Yi),
+        // Hangul Jamo Extended-A
+        0xa960..=0xa97c => Some(Hangul),
+        // Hangul Syllables
+        0xac00..=0xd7a3 => Some(Hangul),
+        // Hangul Jamo Extended-B
+        0xd7b0..=0xd7c6 | 0xd7cb..=0xd7fb => Some(Hangul),
+        // CJK Compatibility Ideographs (canonical decompositions are ordinary Han)
+        0xf900..=0xfaff => Some(Han),
+        // Vertical Forms + CJK Compatibility Forms
+        0xfe10..=0xfe19 | 0xfe30..=0xfe52 | 0xfe54..=0xfe66 | 0xfe68..=0xfe6b => Some(Other),
+        // Fullwidth ASCII and punctuation up to the fullwidth corner brackets
+        0xff01..=0xff60 => Some(FullwidthForms),
+        // Halfwidth Katakana, Hangul and punctuation
+        0xff61..=0xffbe
+        | 0xffc2..=0xffc7
+        | 0xffca..=0xffcf
+        | 0xffd2..=0xffd7
+        | 0xffda..=0xffdc
+        | 0xffe8..=0xffee => Some(HalfwidthForms),
+        // Fullwidth currency symbols
+        0xffe0..=0xffe6 => Some(FullwidthForms),
+        // Ideographic Symbols and Punctuation + Vietnamese Extensions
+        0x16fe0..=0x16fe4 | 0x16ff0..=0x16ff6 => Some(Other),
+        // Tangut Ideographs + Components + Supplement
+        0x17000..=0x18cd5 | 0x18cff..=0x18d1e | 0x18d80..=0x18df2 => Some(Tangut),
+        // Kana Extended-B
+        0x1aff0..=0x1aff3 | 0x1aff5..=0x1affb | 0x1affd..=0x1affe => Some(Katakana),
+        // Kana Extended-A + Kana Supplement (archaic hiragana/katakana)
+        0x1b000..=0x1b122 | 0x1b132 | 0x1b155 | 0x1b164..=0x1b167 => Some(Katakana),
+        0x1b150..=0x1b152 => Some(Hiragana),
+        // Nushu
+        0x1b170..=0x1b2fb => Some(Nushu),
+        // Tai Xuan Jing Symbols + Counting Rod Numerals
+        0x1d300..=0x1d356 | 0x1d360..=0x1d376 => Some(Other),
+        // Enclosed Ideographic Supplement
+        0x1f200
+        | 0x1f202
+        | 0x1f210..=0x1f219
+        | 0x1f21b..=0x1f22e
+        | 0x1f230..=0x1f231
+        | 0x1f237
+        | 0x1f23b
+        | 0x1f240..=0x1f248
+        | 0x1f260..=0x1f265 => Some(Other),
+        // CJK Unified Ideographs Extension B, C, D, E, F, G, H, I
+        0x20000..=0x3fffd => Some(Han),
+        _ => None,
+    }
+}
+
 /// 包括的CJK文字判定 (Unicode 16準拠)
 ///
 /// Unicode 16仕様に基づく最も包括的なCJK文字判定を提供します。
@@ -18,7 +135,7 @@
 /// - 八卦 (U+2630-U+2637) - ☰☷など易経記号
 /// - 太極 (U+268A-U+268F) - ⚊⚏など陰陽記号
 ///
-/// ### CJK部首・記号体系  
+/// ### CJK部首・記号体系
 /// - CJK部首補助 (U+2E80-U+2E99, U+2E9B-U+2EF3) - 康熙部首の補助文字
 /// - 康熙部首 (U+2F00-U+2FD5) - 214の基本部首
 /// - 漢字記述文字 (U+2FF0-U+303E) - 漢字構造記述＋CJK記号句読点
@@ -51,7 +168,7 @@
 /// ## 技術仕様
 ///
 /// ### パフォーマンス最適化
-/// - 時間計算量: O(1) - `matches!`マクロによる定数時間判定
+/// - 時間計算量: O(1) - スクリプト分類テーブルを1回引くだけの定数時間判定
 /// - 分岐予測: Rustコンパイラーによる最適化済み範囲マッチング
 /// - メモリ効率: 分岐テーブル生成による高速判定
 ///
@@ -78,76 +195,12 @@
 /// - 実装参考: Markdown CJK Friendly Project
 /// - ICU実装: International Components for Unicode
 pub fn is_cjk(c: char) -> bool {
-    // Comprehensive CJK detection based on Unicode 16 specification
-    // Provides precise character-level CJK identification for optimal slug generation
-    matches!(
-        u32::from(c),
-        0x1100..=0x11ff   // Hangul Jamo
-        | 0x20a9          // Won Sign (₩)
-        | 0x2329..=0x232a // Left/Right-Pointing Angle Bracket
-        | 0x2630..=0x2637 // Trigrams for Divination
-        | 0x268a..=0x268f // Digrams/Monograms
-        | 0x2e80..=0x2e99 // CJK Radicals Supplement (Part 1)
-        | 0x2e9b..=0x2ef3 // CJK Radicals Supplement (Part 2)
-        | 0x2f00..=0x2fd5 // Kangxi Radicals
-        | 0x2ff0..=0x303e // Ideographic Description Characters + CJK Symbols and Punctuation
-        | 0x3041..=0x3096 // Hiragana
-        | 0x3099..=0x30ff // Combining Marks + Katakana
-        | 0x3105..=0x312f // Bopomofo
-        | 0x3131..=0x318e // Hangul Compatibility Jamo
-        | 0x3190..=0x31e5 // Kanbun + CJK Strokes + Katakana Phonetic Extensions + Enclosed CJK Letters and Months (Part 1)
-        | 0x31ef..=0x321e // Enclosed CJK Letters and Months (Part 2)
-        | 0x3220..=0x3247 // Enclosed CJK Letters and Months (Part 3)
-        | 0x3250..=0xa48c // CJK Compatibility + Yi Syllables + Yi Radicals
-        | 0xa490..=0xa4c6 // Yi Radicals
-        | 0xa960..=0xa97c // Hangul Jamo Extended-A
-        | 0xac00..=0xd7a3 // Hangul Syllables
-        | 0xd7b0..=0xd7c6 // Hangul Jamo Extended-B
-        | 0xd7cb..=0xd7fb // Hangul Jamo Extended-B (Part 2)
-        | 0xf900..=0xfaff // CJK Compatibility Ideographs
-        | 0xfe10..=0xfe19 // Vertical Forms
-        | 0xfe30..=0xfe52 // CJK Compatibility Forms (Part 1)
-        | 0xfe54..=0xfe66 // CJK Compatibility Forms (Part 2)
-        | 0xfe68..=0xfe6b // CJK Compatibility Forms (Part 3)
-        | 0xff01..=0xffbe // Halfwidth and Fullwidth Forms (Part 1)
-        | 0xffc2..=0xffc7 // Halfwidth and Fullwidth Forms (Part 2)
-        | 0xffca..=0xffcf // Halfwidth and Fullwidth Forms (Part 3)
-        | 0xffd2..=0xffd7 // Halfwidth and Fullwidth Forms (Part 4)
-        | 0xffda..=0xffdc // Halfwidth and Fullwidth Forms (Part 5)
-        | 0xffe0..=0xffe6 // Halfwidth and Fullwidth Forms (Part 6)
-        | 0xffe8..=0xffee // Halfwidth and Fullwidth Forms (Part 7)
-        | 0x16fe0..=0x16fe4 // Ideographic Symbols and Punctuation
-        | 0x16ff0..=0x16ff6 // Vietnamese Extensions
-        | 0x17000..=0x18cd5 // Tangut Ideographs + Tangut Components
-        | 0x18cff..=0x18d1e // Tangut Supplement
-        | 0x18d80..=0x18df2 // Tangut Supplement (Part 2)
-        | 0x1aff0..=0x1aff3 // Kana Extended-B (Part 1)
-        | 0x1aff5..=0x1affb // Kana Extended-B (Part 2)
-        | 0x1affd..=0x1affe // Kana Extended-B (Part 3)
-        | 0x1b000..=0x1b122 // Kana Extended-A + Kana Supplement
-        | 0x1b132          // Kana Supplement (Single)
-        | 0x1b150..=0x1b152 // Kana Supplement (Part 2)
-        | 0x1b155          // Kana Supplement (Single)
-        | 0x1b164..=0x1b167 // Kana Supplement (Part 3)
-        | 0x1b170..=0x1b2fb // Nushu
-        | 0x1d300..=0x1d356 // Tai Xuan Jing Symbols
-        | 0x1d360..=0x1d376 // Counting Rod Numerals
-        | 0x1f200          // Enclosed Ideographic Supplement (Single)
-        | 0x1f202          // Enclosed Ideographic Supplement (Single)
-        | 0x1f210..=0x1f219 // Enclosed Ideographic Supplement (Part 1)
-        | 0x1f21b..=0x1f22e // Enclosed Ideographic Supplement (Part 2)
-        | 0x1f230..=0x1f231 // Enclosed Ideographic Supplement (Part 3)
-        | 0x1f237          // Enclosed Ideographic Supplement (Single)
-        | 0x1f23b          // Enclosed Ideographic Supplement (Single)
-        | 0x1f240..=0x1f248 // Enclosed Ideographic Supplement (Part 4)
-        | 0x1f260..=0x1f265 // Enclosed Ideographic Supplement (Part 5)
-        | 0x20000..=0x3fffd // CJK Unified Ideographs Extension B, C, D, E, F, G, H, I
-    )
+    cjk_script(c).is_some()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::is_cjk;
+    use super::{cjk_script, is_cjk, CjkScript};
 
     #[test]
     fn check_cjk_characters() {
@@ -458,4 +511,31 @@ mod tests {
         assert!(!is_cjk('!')); // ASCII exclamation (not fullwidth)
         assert!(!is_cjk('A')); // ASCII letter (not fullwidth)
     }
+
+    #[test]
+    fn classify_script_per_family() {
+        assert_eq!(cjk_script('漢'), Some(CjkScript::Han));
+        assert_eq!(cjk_script('あ'), Some(CjkScript::Hiragana));
+        assert_eq!(cjk_script('ア'), Some(CjkScript::Katakana));
+        assert_eq!(cjk_script('가'), Some(CjkScript::Hangul));
+        assert_eq!(cjk_script('\u{3105}'), Some(CjkScript::Bopomofo)); // ㄅ
+        assert_eq!(cjk_script('\u{A490}'), Some(CjkScript::Yi));
+        assert_eq!(cjk_script('\u{17000}'), Some(CjkScript::Tangut));
+        assert_eq!(cjk_script('\u{1B170}'), Some(CjkScript::Nushu));
+        assert_eq!(cjk_script('\u{FF21}'), Some(CjkScript::FullwidthForms)); // Ａ
+        assert_eq!(cjk_script('\u{FF71}'), Some(CjkScript::HalfwidthForms)); // ｱ
+        assert_eq!(cjk_script('\u{F900}'), Some(CjkScript::Han)); // compatibility ideograph
+        assert_eq!(cjk_script('a'), None);
+    }
+
+    #[test]
+    fn cjk_script_agrees_with_is_cjk() {
+        // `is_cjk` must stay a thin wrapper: every classified char is CJK and
+        // vice versa, across every codepoint this module covers.
+        for cp in 0u32..0x4_0000 {
+            if let Some(c) = char::from_u32(cp) {
+                assert_eq!(cjk_script(c).is_some(), is_cjk(c), "mismatch at U+{cp:04X}");
+            }
+        }
+    }
 }
@@ -0,0 +1,5 @@
+pub mod canonicalize_cjk;
+pub mod char_width;
+pub mod fold_width;
+pub mod index_bucket;
+pub mod is_cjk;